@@ -0,0 +1,112 @@
+//! SQL directive annotations parsed from leading comment lines.
+//!
+//! Borrows the annotation-in-comments convention from tools like Windmill:
+//! execution settings live as `-- key: value` comments directly above the
+//! query text, so they travel with the SQL wherever it's copied.
+
+use std::time::Duration;
+
+/// Execution settings parsed from the leading `-- key: value` comment block
+/// of a SQL string.
+///
+/// Recognized directives: `performance`, `timeout` (seconds), `limit` (row
+/// count), and `format` (`json` or `csv`). Unknown directives, and any line
+/// once the leading comment block ends, are ignored rather than errored, so
+/// queries stay portable across tools that don't understand them.
+///
+/// [`crate::DuneClient::execute_sql`] applies `performance` automatically,
+/// and [`crate::DuneClient::run_sql`] applies both `performance` and `limit`
+/// (the latter on its final results fetch); `timeout` and `format` are
+/// parsed here for callers that want to act on them directly (for example,
+/// routing to [`crate::DuneClient::get_execution_results_csv`] when
+/// `format: csv` is set), since neither has a natural effect on those two
+/// JSON-returning, explicitly-timed methods.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SqlAnnotations {
+    /// Parsed from `-- performance: <tier>`.
+    pub performance: Option<String>,
+    /// Parsed from `-- timeout: <seconds>`.
+    pub timeout: Option<Duration>,
+    /// Parsed from `-- limit: <rows>`.
+    pub limit: Option<u64>,
+    /// Parsed from `-- format: <json|csv>`.
+    pub format: Option<String>,
+}
+
+impl SqlAnnotations {
+    /// Parses the contiguous block of leading `--`-prefixed lines in `sql`.
+    ///
+    /// Scanning stops at the first line that isn't a `--` comment, so
+    /// directives must sit immediately atop the query with no gaps.
+    pub fn parse(sql: &str) -> Self {
+        let mut annotations = Self::default();
+
+        for line in sql.lines() {
+            let Some(comment) = line.trim_start().strip_prefix("--") else {
+                break;
+            };
+
+            let Some((key, value)) = comment.trim().split_once(':') else {
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+
+            match key {
+                "performance" => annotations.performance = Some(value.to_string()),
+                "timeout" => annotations.timeout = value.parse().ok().map(Duration::from_secs),
+                "limit" => annotations.limit = value.parse().ok(),
+                "format" => annotations.format = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        annotations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_all_recognized_directives() {
+        let sql =
+            "-- performance: large\n-- timeout: 120\n-- limit: 5000\n-- format: csv\nSELECT 1";
+        let annotations = SqlAnnotations::parse(sql);
+
+        assert_eq!(annotations.performance, Some("large".to_string()));
+        assert_eq!(annotations.timeout, Some(Duration::from_secs(120)));
+        assert_eq!(annotations.limit, Some(5000));
+        assert_eq!(annotations.format, Some("csv".to_string()));
+    }
+
+    #[test]
+    fn ignores_unknown_directives() {
+        let sql = "-- performance: large\n-- retries: 3\nSELECT 1";
+        let annotations = SqlAnnotations::parse(sql);
+
+        assert_eq!(annotations.performance, Some("large".to_string()));
+        assert_eq!(
+            annotations,
+            SqlAnnotations {
+                performance: Some("large".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn stops_at_first_non_comment_line() {
+        let sql = "-- performance: large\nSELECT 1\n-- limit: 5000";
+        let annotations = SqlAnnotations::parse(sql);
+
+        assert_eq!(annotations.performance, Some("large".to_string()));
+        assert_eq!(annotations.limit, None);
+    }
+
+    #[test]
+    fn no_leading_comments_yields_defaults() {
+        let annotations = SqlAnnotations::parse("SELECT 1");
+        assert_eq!(annotations, SqlAnnotations::default());
+    }
+}
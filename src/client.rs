@@ -1,9 +1,17 @@
+use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 
-use reqwest::{Client, header};
+use futures_core::Stream;
+use reqwest::{header, Client};
 
 use crate::{
+    annotations::SqlAnnotations,
     error::{DuneError, Result},
+    from_row::{self, FromRow},
+    interceptor::{DuneInterceptor, RequestCtx, ResponseCtx},
+    params::{ParameterKinds, QueryParameters},
+    retry::{self, RetryPolicy},
     types::*,
 };
 
@@ -11,10 +19,99 @@ const BASE_URL: &str = "https://api.dune.com/api";
 const DEFAULT_TIMEOUT_SECS: u64 = 30;
 
 /// Client for interacting with the Dune Analytics API.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct DuneClient {
     client: Client,
     base_url: String,
+    retry_policy: RetryPolicy,
+    interceptors: Vec<Arc<dyn DuneInterceptor>>,
+}
+
+impl fmt::Debug for DuneClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DuneClient")
+            .field("base_url", &self.base_url)
+            .field("retry_policy", &self.retry_policy)
+            .field("interceptors", &self.interceptors.len())
+            .finish()
+    }
+}
+
+/// Builder for configuring a [`DuneClient`] beyond the defaults used by
+/// [`DuneClient::new`].
+#[derive(Clone, Default)]
+pub struct DuneClientBuilder {
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+    interceptors: Vec<Arc<dyn DuneInterceptor>>,
+}
+
+impl fmt::Debug for DuneClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DuneClientBuilder")
+            .field("base_url", &self.base_url)
+            .field("retry_policy", &self.retry_policy)
+            .field("interceptors", &self.interceptors.len())
+            .finish()
+    }
+}
+
+impl DuneClientBuilder {
+    /// Starts a builder for the given API key, using the default base URL
+    /// and retry policy.
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            base_url: BASE_URL.to_string(),
+            retry_policy: RetryPolicy::default(),
+            interceptors: Vec::new(),
+        }
+    }
+
+    /// Overrides the base URL requests are sent to.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Overrides the policy used to retry transient `429`/`5xx` responses.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Registers an interceptor, invoked around every request this client
+    /// makes. Interceptors run in registration order.
+    pub fn with_interceptor(mut self, interceptor: impl DuneInterceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Builds the configured [`DuneClient`].
+    pub fn build(self) -> Result<DuneClient> {
+        if self.api_key.is_empty() {
+            return Err(DuneError::InvalidApiKey);
+        }
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            "X-Dune-Api-Key",
+            header::HeaderValue::from_str(&self.api_key).map_err(|_| DuneError::InvalidApiKey)?,
+        );
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+            .build()?;
+
+        Ok(DuneClient {
+            client,
+            base_url: self.base_url,
+            retry_policy: self.retry_policy,
+            interceptors: self.interceptors,
+        })
+    }
 }
 
 impl DuneClient {
@@ -32,31 +129,29 @@ impl DuneClient {
     /// let client = DuneClient::new("your-api-key").unwrap();
     /// ```
     pub fn new(api_key: impl Into<String>) -> Result<Self> {
-        Self::with_base_url(api_key, BASE_URL)
+        Self::builder(api_key).build()
     }
 
     /// Creates a new Dune client with a custom base URL.
     pub fn with_base_url(api_key: impl Into<String>, base_url: impl Into<String>) -> Result<Self> {
-        let api_key = api_key.into();
-        if api_key.is_empty() {
-            return Err(DuneError::InvalidApiKey);
-        }
-
-        let mut headers = header::HeaderMap::new();
-        headers.insert(
-            "X-Dune-Api-Key",
-            header::HeaderValue::from_str(&api_key).map_err(|_| DuneError::InvalidApiKey)?,
-        );
-
-        let client = Client::builder()
-            .default_headers(headers)
-            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
-            .build()?;
+        Self::builder(api_key).base_url(base_url).build()
+    }
 
-        Ok(Self {
-            client,
-            base_url: base_url.into(),
-        })
+    /// Starts a [`DuneClientBuilder`] for configuring retries, the base URL,
+    /// or other options beyond what [`DuneClient::new`] offers.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arrakis::{DuneClient, RetryPolicy};
+    ///
+    /// let client = DuneClient::builder("your-api-key")
+    ///     .retry_policy(RetryPolicy::default())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder(api_key: impl Into<String>) -> DuneClientBuilder {
+        DuneClientBuilder::new(api_key)
     }
 
     // ==================== Execute Endpoints ====================
@@ -79,10 +174,17 @@ impl DuneClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Leading `-- key: value` comment lines are parsed as directives (see
+    /// [`SqlAnnotations`]); a `-- performance: <tier>` directive sets the
+    /// execution tier for this call.
     pub async fn execute_sql(&self, sql: impl Into<String>) -> Result<ExecuteResponse> {
+        let sql = sql.into();
+        let annotations = SqlAnnotations::parse(&sql);
+
         let request = ExecuteSqlRequest {
-            sql: sql.into(),
-            ..Default::default()
+            sql,
+            performance: annotations.performance,
         };
         self.execute_sql_with_options(request).await
     }
@@ -94,9 +196,8 @@ impl DuneClient {
     ) -> Result<ExecuteResponse> {
         let url = format!("{}/v1/sql/execute", self.base_url);
 
-        let response = self.client.post(&url).json(&request).send().await?;
-
-        self.handle_response(response).await
+        self.handle_response(self.client.post(&url).json(&request), None)
+            .await
     }
 
     /// Executes a saved query by its ID.
@@ -130,9 +231,8 @@ impl DuneClient {
     ) -> Result<ExecuteResponse> {
         let url = format!("{}/v1/query/{}/execute", self.base_url, query_id);
 
-        let response = self.client.post(&url).json(&request).send().await?;
-
-        self.handle_response(response).await
+        self.handle_response(self.client.post(&url).json(&request), None)
+            .await
     }
 
     /// Executes a query pipeline with all its dependencies.
@@ -153,9 +253,8 @@ impl DuneClient {
     ) -> Result<PipelineExecuteResponse> {
         let url = format!("{}/v1/query/{}/pipeline/execute", self.base_url, query_id);
 
-        let response = self.client.post(&url).json(&request).send().await?;
-
-        self.handle_response(response).await
+        self.handle_response(self.client.post(&url).json(&request), None)
+            .await
     }
 
     // ==================== Status & Results Endpoints ====================
@@ -184,9 +283,7 @@ impl DuneClient {
     ) -> Result<ExecutionStatusResponse> {
         let url = format!("{}/v1/execution/{}/status", self.base_url, execution_id);
 
-        let response = self.client.get(&url).send().await?;
-
-        self.handle_response(response).await
+        self.handle_response(self.client.get(&url), None).await
     }
 
     /// Gets the results of a query execution in JSON format.
@@ -210,14 +307,11 @@ impl DuneClient {
     ) -> Result<ExecutionResultsResponse> {
         let url = format!("{}/v1/execution/{}/results", self.base_url, execution_id);
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&options.to_query_params())
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        self.handle_response(
+            self.client.get(&url).query(&options.to_query_params()),
+            None,
+        )
+        .await
     }
 
     /// Gets the results of a query execution in CSV format.
@@ -241,14 +335,11 @@ impl DuneClient {
             self.base_url, execution_id
         );
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&options.to_query_params())
-            .send()
-            .await?;
-
-        self.handle_text_response(response).await
+        self.handle_text_response(
+            self.client.get(&url).query(&options.to_query_params()),
+            None,
+        )
+        .await
     }
 
     /// Gets the latest results of a saved query in JSON format.
@@ -284,14 +375,43 @@ impl DuneClient {
     ) -> Result<ExecutionResultsResponse> {
         let url = format!("{}/v1/query/{}/results", self.base_url, query_id);
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&options.to_query_params())
-            .send()
-            .await?;
+        self.handle_response(
+            self.client.get(&url).query(&options.to_query_params()),
+            None,
+        )
+        .await
+    }
 
-        self.handle_response(response).await
+    /// Gets the latest results of a saved query and decodes each row into `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_id` - The ID of the saved query.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> arrakis::Result<()> {
+    /// use arrakis::DuneClient;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Row {
+    ///     address: String,
+    ///     balance: f64,
+    /// }
+    ///
+    /// let client = DuneClient::new("your-api-key")?;
+    /// let rows: Vec<Row> = client.get_latest_results_as(1234567).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_latest_results_as<T: FromRow>(&self, query_id: u64) -> Result<Vec<T>> {
+        let results = self.get_latest_results(query_id).await?;
+        let Some(result) = results.result else {
+            return Ok(Vec::new());
+        };
+        from_row::decode_rows(&result.rows, &result.metadata.column_names)
     }
 
     /// Gets the latest results of a saved query in CSV format.
@@ -312,14 +432,110 @@ impl DuneClient {
     ) -> Result<String> {
         let url = format!("{}/v1/query/{}/results/csv", self.base_url, query_id);
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&options.to_query_params())
-            .send()
-            .await?;
+        self.handle_text_response(
+            self.client.get(&url).query(&options.to_query_params()),
+            None,
+        )
+        .await
+    }
+
+    // ==================== Streaming Endpoints ====================
 
-        self.handle_text_response(response).await
+    /// Streams every row of an execution's results, transparently paginating
+    /// in batches of `page_size`.
+    ///
+    /// Each page is only fetched once the previous page's rows have been
+    /// consumed, so a multi-million-row export can be processed incrementally
+    /// without holding the full result set in memory. A per-page HTTP error
+    /// is yielded as a stream item rather than panicking, ending the stream.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> arrakis::Result<()> {
+    /// use arrakis::DuneClient;
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let client = DuneClient::new("your-api-key")?;
+    /// let rows: Vec<_> = client
+    ///     .row_stream("01234567-89ab-cdef-0123-456789abcdef", 1000)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn row_stream(
+        &self,
+        execution_id: &str,
+        page_size: u64,
+    ) -> impl Stream<Item = Result<Row>> + '_ {
+        let execution_id = execution_id.to_string();
+        async_stream::try_stream! {
+            let mut offset = 0u64;
+
+            loop {
+                let options = ResultOptions {
+                    limit: Some(page_size),
+                    offset: Some(offset),
+                    ..Default::default()
+                };
+                let page = self
+                    .get_execution_results_with_options(&execution_id, options)
+                    .await?;
+
+                let Some(result) = page.result else {
+                    break;
+                };
+
+                let row_count = result.rows.len() as u64;
+                for row in result.rows {
+                    yield row;
+                }
+
+                match result.metadata.next_offset {
+                    Some(next_offset) if row_count > 0 => offset = next_offset,
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    /// Streams every row of a saved query's latest results, transparently
+    /// paginating in batches of `page_size`. See [`DuneClient::row_stream`]
+    /// for pagination and error-handling behavior.
+    pub fn latest_results_stream(
+        &self,
+        query_id: u64,
+        page_size: u64,
+    ) -> impl Stream<Item = Result<Row>> + '_ {
+        async_stream::try_stream! {
+            let mut offset = 0u64;
+
+            loop {
+                let options = ResultOptions {
+                    limit: Some(page_size),
+                    offset: Some(offset),
+                    ..Default::default()
+                };
+                let page = self
+                    .get_latest_results_with_options(query_id, options)
+                    .await?;
+
+                let Some(result) = page.result else {
+                    break;
+                };
+
+                let row_count = result.rows.len() as u64;
+                for row in result.rows {
+                    yield row;
+                }
+
+                match result.metadata.next_offset {
+                    Some(next_offset) if row_count > 0 => offset = next_offset,
+                    _ => break,
+                }
+            }
+        }
     }
 
     // ==================== Cancel Endpoint ====================
@@ -347,9 +563,7 @@ impl DuneClient {
     pub async fn cancel_execution(&self, execution_id: &str) -> Result<CancelExecutionResponse> {
         let url = format!("{}/v1/execution/{}/cancel", self.base_url, execution_id);
 
-        let response = self.client.post(&url).send().await?;
-
-        self.handle_response(response).await
+        self.handle_response(self.client.post(&url), None).await
     }
 
     // ==================== Convenience Methods ====================
@@ -380,16 +594,71 @@ impl DuneClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Honors `-- performance:` and `-- limit:` directives (see
+    /// [`SqlAnnotations`]) on the execution request and the final results
+    /// fetch, respectively. A `-- timeout:` directive has no effect here:
+    /// the explicit `timeout` argument is always authoritative, since
+    /// explicit method arguments take precedence over comment directives.
     pub async fn run_sql(
         &self,
         sql: impl Into<String>,
         timeout: Duration,
     ) -> Result<ExecutionResultsResponse> {
-        let execute_response = self.execute_sql(sql).await?;
-        self.wait_for_results(&execute_response.execution_id, timeout)
+        let sql = sql.into();
+        let annotations = SqlAnnotations::parse(&sql);
+
+        let request = ExecuteSqlRequest {
+            sql,
+            performance: annotations.performance,
+        };
+        let execute_response = self.execute_sql_with_options(request).await?;
+
+        let options = ResultOptions {
+            limit: annotations.limit,
+            ..Default::default()
+        };
+        self.wait_for_results_with_options(&execute_response.execution_id, timeout, options)
             .await
     }
 
+    /// Executes a SQL query, waits for the results, and decodes each row into `T`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> arrakis::Result<()> {
+    /// use std::time::Duration;
+    ///
+    /// use arrakis::DuneClient;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct TransferRow {
+    ///     block_time: String,
+    ///     amount: f64,
+    /// }
+    ///
+    /// let client = DuneClient::new("your-api-key")?;
+    /// let rows: Vec<TransferRow> = client.run_sql_as(
+    ///     "SELECT block_time, amount FROM ethereum.transfers LIMIT 10",
+    ///     Duration::from_secs(60),
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_sql_as<T: FromRow>(
+        &self,
+        sql: impl Into<String>,
+        timeout: Duration,
+    ) -> Result<Vec<T>> {
+        let results = self.run_sql(sql, timeout).await?;
+        let Some(result) = results.result else {
+            return Ok(Vec::new());
+        };
+        from_row::decode_rows(&result.rows, &result.metadata.column_names)
+    }
+
     /// Executes a saved query and waits for the results.
     pub async fn run_query(
         &self,
@@ -401,6 +670,51 @@ impl DuneClient {
             .await
     }
 
+    /// Executes a saved query with bound parameters and waits for the results.
+    ///
+    /// `expected_kinds` is checked against `params` via
+    /// [`QueryParameters::validate`] before the query is executed, returning
+    /// [`DuneError::InvalidParameter`] on the first kind mismatch; pass an
+    /// empty map to skip validation for parameters you don't have metadata
+    /// for.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> arrakis::Result<()> {
+    /// use std::time::Duration;
+    ///
+    /// use arrakis::{DuneClient, ParameterKinds, QueryParameters};
+    ///
+    /// let client = DuneClient::new("your-api-key")?;
+    /// let params = QueryParameters::new()
+    ///     .text("chain", "ethereum")
+    ///     .number("min_amount", 1000.0);
+    /// let expected_kinds = ParameterKinds::from([("chain", "text"), ("min_amount", "number")]);
+    /// let results = client
+    ///     .run_query_with_params(1234567, params, &expected_kinds, Duration::from_secs(60))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_query_with_params(
+        &self,
+        query_id: u64,
+        params: QueryParameters,
+        expected_kinds: &ParameterKinds<'_>,
+        timeout: Duration,
+    ) -> Result<ExecutionResultsResponse> {
+        params.validate(expected_kinds)?;
+
+        let request = ExecuteQueryRequest {
+            query_parameters: params.into_map(),
+            ..Default::default()
+        };
+        let execute_response = self.execute_query_with_options(query_id, request).await?;
+        self.wait_for_results(&execute_response.execution_id, timeout)
+            .await
+    }
+
     /// Waits for a query execution to complete and returns the results.
     ///
     /// # Arguments
@@ -411,8 +725,27 @@ impl DuneClient {
         &self,
         execution_id: &str,
         timeout: Duration,
+    ) -> Result<ExecutionResultsResponse> {
+        self.wait_for_results_with_options(execution_id, timeout, ResultOptions::default())
+            .await
+    }
+
+    /// Waits for a query execution to complete and returns the results,
+    /// fetched with the given [`ResultOptions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `execution_id` - The execution ID to wait for.
+    /// * `timeout` - Maximum time to wait.
+    /// * `options` - Options applied to the final results fetch.
+    pub async fn wait_for_results_with_options(
+        &self,
+        execution_id: &str,
+        timeout: Duration,
+        options: ResultOptions,
     ) -> Result<ExecutionResultsResponse> {
         let start = std::time::Instant::now();
+        let deadline = start + timeout;
         let poll_interval = Duration::from_secs(1);
 
         loop {
@@ -422,11 +755,15 @@ impl DuneClient {
                 });
             }
 
-            let status = self.get_execution_status(execution_id).await?;
+            let status = self
+                .get_execution_status_before(execution_id, deadline)
+                .await?;
 
             match status.state {
                 ExecutionState::Completed => {
-                    return self.get_execution_results(execution_id).await;
+                    return self
+                        .get_execution_results_before(execution_id, options, deadline)
+                        .await;
                 }
                 ExecutionState::Failed => {
                     return Err(DuneError::ExecutionFailed {
@@ -448,15 +785,154 @@ impl DuneClient {
 
     // ==================== Internal Helpers ====================
 
+    /// Like [`DuneClient::get_execution_status`], but caps retry backoff at
+    /// `deadline` for use inside [`DuneClient::wait_for_results_with_options`]'s
+    /// poll loop, instead of the public method's un-deadlined retrying.
+    async fn get_execution_status_before(
+        &self,
+        execution_id: &str,
+        deadline: std::time::Instant,
+    ) -> Result<ExecutionStatusResponse> {
+        let url = format!("{}/v1/execution/{}/status", self.base_url, execution_id);
+
+        self.handle_response(self.client.get(&url), Some(deadline))
+            .await
+    }
+
+    /// Like [`DuneClient::get_execution_results_with_options`], but caps retry
+    /// backoff at `deadline`. See [`DuneClient::get_execution_status_before`].
+    async fn get_execution_results_before(
+        &self,
+        execution_id: &str,
+        options: ResultOptions,
+        deadline: std::time::Instant,
+    ) -> Result<ExecutionResultsResponse> {
+        let url = format!("{}/v1/execution/{}/results", self.base_url, execution_id);
+
+        self.handle_response(
+            self.client.get(&url).query(&options.to_query_params()),
+            Some(deadline),
+        )
+        .await
+    }
+
+    /// Builds the [`RequestCtx`] for `request` and runs every interceptor's
+    /// `before_request`, returning the (possibly header-modified) request
+    /// along with the method/URL for the matching `after_response` call.
+    fn before_request(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> (reqwest::RequestBuilder, reqwest::Method, String) {
+        let Some(built) = request.try_clone().and_then(|b| b.build().ok()) else {
+            return (request, reqwest::Method::GET, String::new());
+        };
+
+        let mut ctx = RequestCtx {
+            method: built.method().clone(),
+            url: built.url().to_string(),
+            body: built
+                .body()
+                .and_then(|b| b.as_bytes())
+                .map(|bytes| String::from_utf8_lossy(bytes).into_owned()),
+            headers: built.headers().clone(),
+        };
+
+        for interceptor in &self.interceptors {
+            interceptor.before_request(&mut ctx);
+        }
+
+        let request = request.headers(ctx.headers);
+        (request, ctx.method, ctx.url)
+    }
+
+    /// Runs every interceptor's `after_response` for a completed (or failed) call.
+    fn after_response(
+        &self,
+        method: reqwest::Method,
+        url: String,
+        status: Option<reqwest::StatusCode>,
+        elapsed: Duration,
+    ) {
+        let ctx = ResponseCtx {
+            method,
+            url,
+            status,
+            elapsed,
+        };
+
+        for interceptor in &self.interceptors {
+            interceptor.after_response(&ctx);
+        }
+    }
+
+    /// Sends `request`, retrying on `429`/`5xx` responses per `self.retry_policy`.
+    ///
+    /// The request body must be clonable (i.e. not a streaming body), since a
+    /// retry re-issues the same request from scratch. If `deadline` is given,
+    /// each computed delay is capped at the time remaining until it, and
+    /// retrying stops (returning the last response as-is) once it's passed -
+    /// this is how [`DuneClient::wait_for_results_with_options`] keeps retry
+    /// backoff from overrunning its caller-supplied `timeout`.
+    async fn send_with_retry(
+        &self,
+        request: reqwest::RequestBuilder,
+        deadline: Option<std::time::Instant>,
+    ) -> Result<reqwest::Response> {
+        let mut attempt = 0u32;
+
+        loop {
+            let attempt_request = request
+                .try_clone()
+                .expect("retryable requests must have a clonable body");
+            let response = attempt_request.send().await?;
+            let status = response.status();
+
+            let retryable = status.as_u16() == 429 || status.is_server_error();
+            if !retryable || attempt >= self.retry_policy.max_retries {
+                return Ok(response);
+            }
+
+            let delay = retry::parse_retry_after(response.headers())
+                .unwrap_or_else(|| self.retry_policy.backoff_delay(attempt));
+
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                if remaining.is_zero() {
+                    return Ok(response);
+                }
+                tokio::time::sleep(delay.min(remaining)).await;
+            } else {
+                tokio::time::sleep(delay).await;
+            }
+
+            attempt += 1;
+        }
+    }
+
     async fn handle_response<T: serde::de::DeserializeOwned>(
         &self,
-        response: reqwest::Response,
+        request: reqwest::RequestBuilder,
+        deadline: Option<std::time::Instant>,
     ) -> Result<T> {
+        let (request, method, url) = self.before_request(request);
+        let start = std::time::Instant::now();
+        let response = self.send_with_retry(request, deadline).await;
+        self.after_response(
+            method,
+            url,
+            response.as_ref().ok().map(|r| r.status()),
+            start.elapsed(),
+        );
+        let response = response?;
         let status = response.status();
 
         if status.is_success() {
             let body = response.text().await?;
             serde_json::from_str(&body).map_err(DuneError::from)
+        } else if status.as_u16() == 429 {
+            Err(DuneError::RateLimited {
+                retry_after: retry::parse_retry_after(response.headers()),
+            })
         } else {
             let body = response.text().await.unwrap_or_default();
 
@@ -475,11 +951,29 @@ impl DuneClient {
         }
     }
 
-    async fn handle_text_response(&self, response: reqwest::Response) -> Result<String> {
+    async fn handle_text_response(
+        &self,
+        request: reqwest::RequestBuilder,
+        deadline: Option<std::time::Instant>,
+    ) -> Result<String> {
+        let (request, method, url) = self.before_request(request);
+        let start = std::time::Instant::now();
+        let response = self.send_with_retry(request, deadline).await;
+        self.after_response(
+            method,
+            url,
+            response.as_ref().ok().map(|r| r.status()),
+            start.elapsed(),
+        );
+        let response = response?;
         let status = response.status();
 
         if status.is_success() {
             Ok(response.text().await?)
+        } else if status.as_u16() == 429 {
+            Err(DuneError::RateLimited {
+                retry_after: retry::parse_retry_after(response.headers()),
+            })
         } else {
             let body = response.text().await.unwrap_or_default();
             Err(DuneError::Api {
@@ -0,0 +1,72 @@
+//! Error types returned by [`crate::DuneClient`].
+
+use thiserror::Error;
+
+/// A specialized `Result` type for Dune client operations.
+pub type Result<T> = std::result::Result<T, DuneError>;
+
+/// Errors that can occur while talking to the Dune Analytics API.
+#[derive(Debug, Error)]
+pub enum DuneError {
+    /// The supplied API key was empty or rejected by the HTTP client.
+    #[error("invalid API key")]
+    InvalidApiKey,
+
+    /// The API responded with a non-2xx status and an error message.
+    #[error("Dune API error: {message}")]
+    Api {
+        /// The error message returned by the API, or the raw response body.
+        message: String,
+    },
+
+    /// `wait_for_results` gave up before the execution finished.
+    #[error("timed out after {seconds} seconds waiting for execution results")]
+    Timeout {
+        /// The timeout that was exceeded, in seconds.
+        seconds: u64,
+    },
+
+    /// The query execution reached the `FAILED` state.
+    #[error("execution failed: {message}")]
+    ExecutionFailed {
+        /// Details about the failure, as reported by the API.
+        message: String,
+    },
+
+    /// The query execution was cancelled before it produced results.
+    #[error("execution was cancelled")]
+    Cancelled,
+
+    /// The API is rate-limiting this client and the configured retry policy
+    /// was exhausted (or retries are disabled).
+    #[error("rate limited by Dune API (retry_after={retry_after:?})")]
+    RateLimited {
+        /// The delay the server asked us to wait, from a `Retry-After` header.
+        retry_after: Option<std::time::Duration>,
+    },
+
+    /// A bound query parameter's kind didn't match the saved query's
+    /// declared parameter kind.
+    #[error("invalid value bound for parameter `{name}`")]
+    InvalidParameter {
+        /// The name of the offending parameter.
+        name: String,
+    },
+
+    /// A result row could not be decoded into the requested type.
+    #[error("failed to decode column `{column}` as `{expected}`")]
+    RowDecode {
+        /// The column (or row, for positional decoding) that failed to decode.
+        column: String,
+        /// The Rust type the caller asked for.
+        expected: String,
+    },
+
+    /// Transport-level failure while sending the request or reading the response.
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+
+    /// The response body was not valid JSON, or didn't match the expected shape.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
@@ -0,0 +1,150 @@
+//! Typed decoding of result [`Row`]s into caller-defined structs or tuples.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::error::{DuneError, Result};
+use crate::types::Row;
+
+/// Decodes a single result row into a Rust value.
+///
+/// Blanket-implemented for any `T: DeserializeOwned`: a row is first tried as
+/// a JSON object (mapping column names onto struct fields, as `serde` derives
+/// expect). If that fails because a field's value doesn't fit the field's
+/// type, the error names that exact column. If it fails because the row's
+/// shape doesn't fit an object at all - for example when `T` is a tuple like
+/// `(A, B)` - it falls back to decoding the row's values positionally, in
+/// `column_names` order (not `Row`'s own iteration order: `Row` is a
+/// `BTreeMap`, so iterating it directly yields columns alphabetically rather
+/// than in result order). This mirrors binding a query result onto a tuple
+/// with `rusqlite`'s `Row::get` helpers, without requiring a separate impl
+/// per arity.
+pub trait FromRow: Sized {
+    /// Builds `Self` from a single result row.
+    ///
+    /// `column_names` gives the row's columns in result order, and is only
+    /// consulted for positional (tuple) decoding.
+    fn from_row(row: &Row, column_names: &[String]) -> Result<Self>;
+}
+
+impl<T> FromRow for T
+where
+    T: DeserializeOwned,
+{
+    fn from_row(row: &Row, column_names: &[String]) -> Result<Self> {
+        let as_object = Value::Object(row.clone());
+        match serde_path_to_error::deserialize(as_object) {
+            Ok(value) => return Ok(value),
+            Err(err) if err.path().to_string() != "." => {
+                // The value reached a named field and didn't fit it - report
+                // that column instead of falling through to positional
+                // decoding, which would either mis-bind the value elsewhere
+                // or blame the whole row.
+                return Err(DuneError::RowDecode {
+                    column: err.path().to_string().trim_start_matches('.').to_string(),
+                    expected: std::any::type_name::<T>().to_string(),
+                });
+            }
+            Err(_) => {
+                // Root-level shape mismatch (e.g. `T` is a tuple, which
+                // doesn't deserialize from a JSON object at all) - fall
+                // back to positional decoding below.
+            }
+        }
+
+        let values: Vec<Value> = column_names
+            .iter()
+            .map(|name| row.get(name).cloned().unwrap_or(Value::Null))
+            .collect();
+        serde_json::from_value(Value::Array(values)).map_err(|_| DuneError::RowDecode {
+            column: "<row>".to_string(),
+            expected: std::any::type_name::<T>().to_string(),
+        })
+    }
+}
+
+/// Decodes every row in `rows` into `T`, short-circuiting on the first error.
+///
+/// `column_names` gives the result set's columns in result order, passed
+/// through to [`FromRow::from_row`] for positional decoding.
+pub(crate) fn decode_rows<T: FromRow>(rows: &[Row], column_names: &[String]) -> Result<Vec<T>> {
+    rows.iter()
+        .map(|row| T::from_row(row, column_names))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(pairs: &[(&str, Value)]) -> Row {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn decodes_struct_by_field_name_regardless_of_column_order() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Account {
+            address: String,
+            balance: f64,
+        }
+
+        // Result order is (balance, address), the reverse of the struct's
+        // field order; decoding by name must not depend on it.
+        let row = row(&[
+            ("address", Value::String("0xabc".to_string())),
+            ("balance", Value::Number(42.into())),
+        ]);
+        let column_names = vec!["balance".to_string(), "address".to_string()];
+
+        let account = Account::from_row(&row, &column_names).unwrap();
+        assert_eq!(
+            account,
+            Account {
+                address: "0xabc".to_string(),
+                balance: 42.0,
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_tuple_in_result_column_order_not_alphabetical_order() {
+        // `SELECT b, a`: result order is (b, a), but Row's BTreeMap iterates
+        // keys alphabetically as (a, b). Positional decoding must follow
+        // column_names, not the map's own order.
+        let row = row(&[
+            ("a", Value::String("A".to_string())),
+            ("b", Value::String("B".to_string())),
+        ]);
+        let column_names = vec!["b".to_string(), "a".to_string()];
+
+        let (first, second) = <(String, String)>::from_row(&row, &column_names).unwrap();
+        assert_eq!(first, "B");
+        assert_eq!(second, "A");
+    }
+
+    #[test]
+    fn reports_the_actual_failing_column_on_type_mismatch() {
+        #[derive(serde::Deserialize, Debug)]
+        struct Account {
+            #[allow(dead_code)]
+            address: String,
+            balance: f64,
+        }
+
+        let row = row(&[
+            ("address", Value::String("0xabc".to_string())),
+            ("balance", Value::String("not a number".to_string())),
+        ]);
+        let column_names = vec!["address".to_string(), "balance".to_string()];
+
+        let err = Account::from_row(&row, &column_names).unwrap_err();
+        match err {
+            DuneError::RowDecode { column, .. } => assert_eq!(column, "balance"),
+            other => panic!("expected RowDecode, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Cross-cutting hooks invoked around every HTTP call.
+
+use std::time::Duration;
+
+use reqwest::{header::HeaderMap, Method, StatusCode};
+
+/// Context for an outgoing request, passed to [`DuneInterceptor::before_request`].
+///
+/// Interceptors can read `method`/`url`/`body` for logging, or insert into
+/// `headers` to add request headers (e.g. a trace ID).
+#[derive(Debug, Clone)]
+pub struct RequestCtx {
+    /// The HTTP method of the outgoing request.
+    pub method: Method,
+    /// The fully-qualified URL, including query parameters.
+    pub url: String,
+    /// The serialized request body, if any.
+    pub body: Option<String>,
+    /// Headers that will be sent with the request. Interceptors may insert
+    /// into this map to add or override headers before the request goes out.
+    pub headers: HeaderMap,
+}
+
+/// Context for a completed request, passed to [`DuneInterceptor::after_response`].
+#[derive(Debug, Clone)]
+pub struct ResponseCtx {
+    /// The method of the request this response belongs to.
+    pub method: Method,
+    /// The URL of the request this response belongs to.
+    pub url: String,
+    /// The HTTP status returned, or `None` if the request failed before a
+    /// response was received (e.g. a connection error).
+    pub status: Option<StatusCode>,
+    /// Total time elapsed from the first attempt to the final response,
+    /// including any retries.
+    pub elapsed: Duration,
+}
+
+/// A hook invoked around every HTTP call `DuneClient` makes.
+///
+/// Register implementations via [`crate::DuneClientBuilder::with_interceptor`]
+/// for cross-cutting concerns like logging, API key redaction, or latency
+/// measurement, without forking the HTTP call sites themselves. Both methods
+/// have no-op defaults so an interceptor can implement just the one it needs.
+pub trait DuneInterceptor: Send + Sync {
+    /// Called just before a request is sent.
+    fn before_request(&self, ctx: &mut RequestCtx) {
+        let _ = ctx;
+    }
+
+    /// Called after a response is received, or after the request ultimately
+    /// fails (in which case `ctx.status` is `None`).
+    fn after_response(&self, ctx: &ResponseCtx) {
+        let _ = ctx;
+    }
+}
+
+/// Built-in interceptor that emits `tracing` events for every request,
+/// logging the method, URL, status, and latency.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingInterceptor;
+
+impl DuneInterceptor for TracingInterceptor {
+    fn before_request(&self, ctx: &mut RequestCtx) {
+        tracing::debug!(method = %ctx.method, url = %ctx.url, "sending Dune API request");
+    }
+
+    fn after_response(&self, ctx: &ResponseCtx) {
+        match ctx.status {
+            Some(status) => tracing::debug!(
+                method = %ctx.method,
+                url = %ctx.url,
+                status = %status,
+                elapsed_ms = ctx.elapsed.as_millis(),
+                "received Dune API response"
+            ),
+            None => tracing::warn!(
+                method = %ctx.method,
+                url = %ctx.url,
+                elapsed_ms = ctx.elapsed.as_millis(),
+                "Dune API request failed"
+            ),
+        }
+    }
+}
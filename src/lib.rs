@@ -77,10 +77,20 @@
 //! # }
 //! ```
 
+mod annotations;
 mod client;
 mod error;
+mod from_row;
+mod interceptor;
+mod params;
+mod retry;
 mod types;
 
-pub use client::DuneClient;
+pub use annotations::SqlAnnotations;
+pub use client::{DuneClient, DuneClientBuilder};
 pub use error::{DuneError, Result};
+pub use from_row::FromRow;
+pub use interceptor::{DuneInterceptor, RequestCtx, ResponseCtx, TracingInterceptor};
+pub use params::{ParameterKinds, ParameterValue, QueryParameters};
+pub use retry::RetryPolicy;
 pub use types::*;
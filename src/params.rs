@@ -0,0 +1,197 @@
+//! Typed named parameters for saved queries.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::error::{DuneError, Result};
+
+/// A single named query parameter value, matching the kinds Dune's saved
+/// queries support.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterValue {
+    /// A free-form text value.
+    Text(String),
+    /// A numeric value.
+    Number(f64),
+    /// A date value, formatted as Dune expects (`YYYY-MM-DD HH:MM:SS`).
+    Date(String),
+    /// One of a fixed set of string choices.
+    Enum(String),
+    /// A list of text values.
+    List(Vec<String>),
+}
+
+impl ParameterValue {
+    fn kind(&self) -> &'static str {
+        match self {
+            ParameterValue::Text(_) => "text",
+            ParameterValue::Number(_) => "number",
+            ParameterValue::Date(_) => "date",
+            ParameterValue::Enum(_) => "enum",
+            ParameterValue::List(_) => "list",
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            ParameterValue::Text(v) | ParameterValue::Date(v) | ParameterValue::Enum(v) => {
+                Value::String(v.clone())
+            }
+            ParameterValue::Number(v) => {
+                serde_json::Number::from_f64(*v).map_or(Value::Null, Value::Number)
+            }
+            ParameterValue::List(values) => {
+                Value::Array(values.iter().cloned().map(Value::String).collect())
+            }
+        }
+    }
+}
+
+/// The expected kind for each named parameter, keyed by parameter name, used
+/// to validate bound values against a saved query's declared parameters.
+pub type ParameterKinds<'a> = HashMap<&'a str, &'a str>;
+
+/// Builds the `query_parameters` map sent alongside a saved-query execution.
+///
+/// This is the analytics analog of binding values into a prepared statement:
+/// build up named, typed values, then hand the result to
+/// [`crate::DuneClient::run_query_with_params`].
+///
+/// # Example
+///
+/// ```
+/// use arrakis::QueryParameters;
+///
+/// let params = QueryParameters::new()
+///     .text("chain", "ethereum")
+///     .number("min_amount", 1000.0)
+///     .list("addresses", vec!["0xabc".to_string(), "0xdef".to_string()]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryParameters {
+    values: HashMap<String, ParameterValue>,
+}
+
+impl QueryParameters {
+    /// Creates an empty parameter set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds a text parameter.
+    pub fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values
+            .insert(name.into(), ParameterValue::Text(value.into()));
+        self
+    }
+
+    /// Binds a numeric parameter.
+    pub fn number(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.values
+            .insert(name.into(), ParameterValue::Number(value));
+        self
+    }
+
+    /// Binds a date parameter, formatted as Dune expects (`YYYY-MM-DD HH:MM:SS`).
+    pub fn date(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values
+            .insert(name.into(), ParameterValue::Date(value.into()));
+        self
+    }
+
+    /// Binds an enum parameter: one of a fixed set of string choices.
+    pub fn enum_value(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values
+            .insert(name.into(), ParameterValue::Enum(value.into()));
+        self
+    }
+
+    /// Binds a list parameter.
+    pub fn list(mut self, name: impl Into<String>, values: Vec<String>) -> Self {
+        self.values
+            .insert(name.into(), ParameterValue::List(values));
+        self
+    }
+
+    /// Validates every bound parameter's kind against `expected_kinds`
+    /// (typically the saved query's declared parameter types), returning
+    /// [`DuneError::InvalidParameter`] on the first mismatch.
+    ///
+    /// Parameters with no entry in `expected_kinds` are left unvalidated, so
+    /// binding stays usable even when the caller doesn't have metadata handy.
+    pub fn validate(&self, expected_kinds: &ParameterKinds<'_>) -> Result<()> {
+        for (name, value) in &self.values {
+            if let Some(expected) = expected_kinds.get(name.as_str()) {
+                if *expected != value.kind() {
+                    return Err(DuneError::InvalidParameter { name: name.clone() });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Serializes the bound values into the `query_parameters` map expected
+    /// by [`crate::ExecuteQueryRequest`].
+    pub fn into_map(self) -> HashMap<String, Value> {
+        self.values
+            .into_iter()
+            .map(|(name, value)| (name, value.to_json()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_map_serializes_each_kind() {
+        let map = QueryParameters::new()
+            .text("chain", "ethereum")
+            .number("min_amount", 1000.0)
+            .date("since", "2026-01-01 00:00:00")
+            .enum_value("tier", "large")
+            .list("addresses", vec!["0xabc".to_string(), "0xdef".to_string()])
+            .into_map();
+
+        assert_eq!(map["chain"], Value::String("ethereum".to_string()));
+        assert_eq!(map["min_amount"], serde_json::json!(1000.0));
+        assert_eq!(
+            map["since"],
+            Value::String("2026-01-01 00:00:00".to_string())
+        );
+        assert_eq!(map["tier"], Value::String("large".to_string()));
+        assert_eq!(map["addresses"], serde_json::json!(["0xabc", "0xdef"]));
+    }
+
+    #[test]
+    fn validate_passes_when_kinds_match() {
+        let params = QueryParameters::new()
+            .text("chain", "ethereum")
+            .number("min_amount", 1000.0);
+        let expected = ParameterKinds::from([("chain", "text"), ("min_amount", "number")]);
+
+        assert!(params.validate(&expected).is_ok());
+    }
+
+    #[test]
+    fn validate_ignores_parameters_with_no_expected_kind() {
+        let params = QueryParameters::new().text("chain", "ethereum");
+        let expected = ParameterKinds::new();
+
+        assert!(params.validate(&expected).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_a_kind_mismatch() {
+        let params = QueryParameters::new().text("min_amount", "not a number");
+        let expected = ParameterKinds::from([("min_amount", "number")]);
+
+        let err = params.validate(&expected).unwrap_err();
+        match err {
+            DuneError::InvalidParameter { name } => assert_eq!(name, "min_amount"),
+            other => panic!("expected InvalidParameter, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Retry and backoff configuration for transient HTTP failures.
+
+use std::time::Duration;
+
+/// Governs how [`crate::DuneClient`] retries requests that fail with a
+/// `429 Too Many Requests` or `5xx` response.
+///
+/// Configured via [`crate::DuneClientBuilder::retry_policy`]. Each retry
+/// waits for the delay given by a `Retry-After` response header if present,
+/// otherwise for an exponentially increasing delay with jitter, capped at
+/// `max_delay`.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retry attempts after the initial request.
+    pub max_retries: u32,
+    /// Base delay used to compute exponential backoff.
+    pub base_delay: Duration,
+    /// Upper bound on any single computed backoff delay.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Disables retries: every request is attempted exactly once.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Computes the backoff delay for the given zero-indexed retry attempt.
+    ///
+    /// `base_delay * 2^attempt` is first capped at `max_delay`; the result is
+    /// half of that capped value plus a random jitter of up to the other half,
+    /// so the delay falls between 50% and 100% of the capped value.
+    pub(crate) fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .checked_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX))
+            .unwrap_or(self.max_delay);
+        let capped = exponential.min(self.max_delay);
+
+        let jitter_ceiling = (capped.as_millis() as u64 / 2).max(1);
+        let jitter = Duration::from_millis(fastrand::u64(0..=jitter_ceiling));
+
+        (capped / 2 + jitter).min(self.max_delay)
+    }
+}
+
+/// Parses a `Retry-After` header value expressed as a number of seconds.
+///
+/// The HTTP-date form isn't supported; such headers are ignored in favor of
+/// the policy's own computed backoff.
+pub(crate) fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    let seconds: u64 = value.trim().parse().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_is_capped_at_max_delay() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(1),
+        };
+
+        for attempt in 0..10 {
+            assert!(policy.backoff_delay(attempt) <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn backoff_delay_grows_with_attempt() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(60),
+        };
+
+        // attempt 0's worst case (100ms, fully jittered) is still below
+        // attempt 3's best case (400ms, no jitter), so the floor strictly
+        // increases with the attempt number.
+        assert!(policy.backoff_delay(3) > policy.backoff_delay(0));
+        assert!(policy.backoff_delay(3) >= Duration::from_millis(400));
+        assert!(policy.backoff_delay(0) <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn parse_retry_after_reads_seconds() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "5".parse().unwrap());
+
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn parse_retry_after_ignores_http_dates() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Wed, 21 Oct 2026 07:28:00 GMT".parse().unwrap(),
+        );
+
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[test]
+    fn parse_retry_after_missing_header() {
+        let headers = reqwest::header::HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! Request and response types for the Dune Analytics API.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A single result row, keyed by column name.
+pub type Row = serde_json::Map<String, serde_json::Value>;
+
+/// The lifecycle state of a query execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionState {
+    /// The execution has been queued but hasn't started yet.
+    Pending,
+    /// The execution is currently running.
+    Executing,
+    /// The execution finished successfully and results are available.
+    Completed,
+    /// The execution failed before producing results.
+    Failed,
+    /// The execution was cancelled via [`crate::DuneClient::cancel_execution`].
+    Cancelled,
+}
+
+/// Response returned when starting a SQL or saved-query execution.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExecuteResponse {
+    /// The ID assigned to this execution, used to poll status and results.
+    pub execution_id: String,
+    /// The initial state of the execution (typically `Pending`).
+    pub state: ExecutionState,
+}
+
+/// Response returned when starting a query pipeline.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PipelineExecuteResponse {
+    /// The ID assigned to the pipeline's root execution.
+    pub execution_id: String,
+    /// The initial state of the execution (typically `Pending`).
+    pub state: ExecutionState,
+}
+
+/// Response returned by [`crate::DuneClient::get_execution_status`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExecutionStatusResponse {
+    /// The execution this status describes.
+    pub execution_id: String,
+    /// The saved query that was executed, if this was a saved-query execution.
+    pub query_id: Option<u64>,
+    /// The current lifecycle state of the execution.
+    pub state: ExecutionState,
+    /// When the execution was submitted, as an RFC 3339 timestamp.
+    pub submitted_at: Option<String>,
+    /// When the execution started running, as an RFC 3339 timestamp.
+    pub execution_started_at: Option<String>,
+    /// When the execution finished, as an RFC 3339 timestamp.
+    pub execution_ended_at: Option<String>,
+}
+
+/// Column and row-count metadata accompanying a result set.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ResultMetadata {
+    /// The result columns, in order.
+    pub column_names: Vec<String>,
+    /// The number of rows in this result set.
+    pub row_count: u64,
+    /// The offset of the next page of rows, if there are more to fetch.
+    pub next_offset: Option<u64>,
+    /// The URI to fetch the next page of rows from, if any.
+    pub next_uri: Option<String>,
+}
+
+/// A page of query results.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExecutionResult {
+    /// The rows returned, each keyed by column name.
+    pub rows: Vec<Row>,
+    /// Metadata describing the columns and pagination state.
+    pub metadata: ResultMetadata,
+}
+
+/// Response returned by the results endpoints.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExecutionResultsResponse {
+    /// The execution these results belong to.
+    pub execution_id: String,
+    /// The current lifecycle state of the execution.
+    pub state: ExecutionState,
+    /// The result set, present once the execution has completed.
+    pub result: Option<ExecutionResult>,
+}
+
+/// Response returned by [`crate::DuneClient::cancel_execution`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CancelExecutionResponse {
+    /// Whether the cancellation request was accepted.
+    pub success: bool,
+}
+
+/// Request body for [`crate::DuneClient::execute_sql_with_options`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExecuteSqlRequest {
+    /// The SQL query to execute.
+    pub sql: String,
+    /// The execution tier to run on (e.g. `"medium"`, `"large"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performance: Option<String>,
+}
+
+/// Request body for [`crate::DuneClient::execute_query_with_options`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExecuteQueryRequest {
+    /// The execution tier to run on (e.g. `"medium"`, `"large"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performance: Option<String>,
+    /// Named parameter values to bind into the saved query.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub query_parameters: HashMap<String, serde_json::Value>,
+}
+
+/// Query parameters accepted by the results endpoints.
+#[derive(Debug, Clone, Default)]
+pub struct ResultOptions {
+    /// Maximum number of rows to return.
+    pub limit: Option<u64>,
+    /// Number of rows to skip before returning results.
+    pub offset: Option<u64>,
+    /// Restrict the response to this subset of columns.
+    pub columns: Option<Vec<String>>,
+    /// Sort the result set by this column before paginating.
+    pub sort_by: Option<String>,
+}
+
+impl ResultOptions {
+    /// Converts these options into the query string parameters expected by
+    /// the results endpoints.
+    pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset", offset.to_string()));
+        }
+        if let Some(columns) = &self.columns {
+            params.push(("columns", columns.join(",")));
+        }
+        if let Some(sort_by) = &self.sort_by {
+            params.push(("sort_by", sort_by.clone()));
+        }
+
+        params
+    }
+}